@@ -0,0 +1,93 @@
+//! Parse-error and ERROR-node diagnostics, surfacing tree-sitter's error
+//! recovery so partially-broken files still yield useful structural
+//! information instead of failing silently.
+
+use std::ops::Range;
+
+use tree_sitter::{Node, Point, Tree};
+
+/// How severe a diagnostic is. Every parse diagnostic today is an error,
+/// but the field is kept separate from [`DiagnosticKind`] so a future
+/// lint-style pass can report warnings through the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+}
+
+/// Whether a diagnostic comes from a MISSING token tree-sitter inserted
+/// during error recovery, or an unexpected ERROR span it couldn't fit
+/// into the grammar at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    Missing,
+    Unexpected,
+}
+
+/// A single parse-error finding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    pub kind: DiagnosticKind,
+    pub severity: Severity,
+    pub message: String,
+    pub byte_range: Range<usize>,
+    pub start_point: Point,
+    pub end_point: Point,
+    /// The source line(s) the diagnostic's node spans, for context.
+    pub snippet: String,
+}
+
+/// Walks `tree` collecting every MISSING and ERROR node, pairing each
+/// with its surrounding source context.
+pub fn diagnostics(tree: &Tree, source: &str) -> Vec<ParseDiagnostic> {
+    let mut diags = Vec::new();
+    collect(tree.root_node(), source, &mut diags);
+    diags
+}
+
+fn collect(node: Node, source: &str, diags: &mut Vec<ParseDiagnostic>) {
+    if node.is_missing() {
+        diags.push(ParseDiagnostic {
+            kind: DiagnosticKind::Missing,
+            severity: Severity::Error,
+            message: format!("missing {}", node.kind()),
+            byte_range: node.byte_range(),
+            start_point: node.start_position(),
+            end_point: node.end_position(),
+            snippet: line_snippet(node, source),
+        });
+    } else if node.is_error() {
+        diags.push(ParseDiagnostic {
+            kind: DiagnosticKind::Unexpected,
+            severity: Severity::Error,
+            message: format!(
+                "unexpected token(s): {:?}",
+                node.utf8_text(source.as_bytes()).unwrap_or_default()
+            ),
+            byte_range: node.byte_range(),
+            start_point: node.start_position(),
+            end_point: node.end_position(),
+            snippet: line_snippet(node, source),
+        });
+    }
+
+    // MISSING and ERROR nodes can themselves contain further MISSING or
+    // ERROR nodes, so keep recursing into every node's children.
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect(child, source, diags);
+    }
+}
+
+/// The full source line(s) a node spans, so a diagnostic reads in
+/// context rather than as a bare byte range.
+fn line_snippet(node: Node, source: &str) -> String {
+    let start_row = node.start_position().row;
+    let end_row = node.end_position().row;
+    source
+        .lines()
+        .enumerate()
+        .filter(|(row, _)| *row >= start_row && *row <= end_row)
+        .map(|(_, line)| line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
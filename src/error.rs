@@ -0,0 +1,25 @@
+//! Crate-wide error type.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors produced while parsing or analyzing source files.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("invalid structural-search query: {0}")]
+    Query(#[from] tree_sitter::QueryError),
+
+    #[error("no language registered for extension of {0}")]
+    UnsupportedExtension(PathBuf),
+}
+
+/// Convenience alias for fallible shannon-insight operations.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,197 @@
+//! Typed AST extraction: converts the untyped tree-sitter node tree into
+//! concrete Rust types, resolving the grammar's `name:`/`body:` fields so
+//! downstream passes don't have to hand-walk cursors. This is the
+//! foundation other analysis passes (metrics, search) can build on.
+
+use std::ops::Range;
+
+use tree_sitter::{Node, Tree};
+
+/// Whether an item or field carries a `pub` visibility modifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub ty: String,
+    pub visibility: Visibility,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub ty: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemTrait {
+    pub name: String,
+    pub methods: Vec<String>,
+    pub byte_range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemStruct {
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub visibility: Visibility,
+    pub byte_range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemEnum {
+    pub name: String,
+    pub variants: Vec<String>,
+    pub byte_range: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemFn {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub return_type: Option<String>,
+    pub byte_range: Range<usize>,
+}
+
+/// A typed, high-level item extracted from a parse tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    Trait(ItemTrait),
+    Struct(ItemStruct),
+    Enum(ItemEnum),
+    Fn(ItemFn),
+}
+
+/// Walks `tree` and extracts every trait, struct, enum, and function into
+/// its typed representation.
+pub fn extract_items(tree: &Tree, source: &str) -> Vec<Item> {
+    let mut items = Vec::new();
+    collect(tree.root_node(), source, &mut items);
+    items
+}
+
+fn collect(node: Node, source: &str, items: &mut Vec<Item>) {
+    match node.kind() {
+        "trait_item" => items.push(Item::Trait(extract_trait(node, source))),
+        "struct_item" => items.push(Item::Struct(extract_struct(node, source))),
+        "enum_item" => items.push(Item::Enum(extract_enum(node, source))),
+        "function_item" => items.push(Item::Fn(extract_fn(node, source))),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect(child, source, items);
+    }
+}
+
+fn text<'a>(node: Node, source: &'a str) -> &'a str {
+    node.utf8_text(source.as_bytes()).unwrap_or_default()
+}
+
+fn field_text(node: Node, field: &str, source: &str) -> String {
+    node.child_by_field_name(field)
+        .map(|n| text(n, source).to_string())
+        .unwrap_or_default()
+}
+
+fn visibility(node: Node) -> Visibility {
+    let mut cursor = node.walk();
+    if node
+        .children(&mut cursor)
+        .any(|child| child.kind() == "visibility_modifier")
+    {
+        Visibility::Public
+    } else {
+        Visibility::Private
+    }
+}
+
+fn extract_trait(node: Node, source: &str) -> ItemTrait {
+    let mut methods = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if matches!(child.kind(), "function_signature_item" | "function_item") {
+                methods.push(field_text(child, "name", source));
+            }
+        }
+    }
+
+    ItemTrait {
+        name: field_text(node, "name", source),
+        methods,
+        byte_range: node.byte_range(),
+    }
+}
+
+fn extract_struct(node: Node, source: &str) -> ItemStruct {
+    let mut fields = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if child.kind() == "field_declaration" {
+                fields.push(Field {
+                    name: field_text(child, "name", source),
+                    ty: field_text(child, "type", source),
+                    visibility: visibility(child),
+                });
+            }
+        }
+    }
+
+    ItemStruct {
+        name: field_text(node, "name", source),
+        fields,
+        visibility: visibility(node),
+        byte_range: node.byte_range(),
+    }
+}
+
+fn extract_enum(node: Node, source: &str) -> ItemEnum {
+    let mut variants = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if child.kind() == "enum_variant" {
+                variants.push(field_text(child, "name", source));
+            }
+        }
+    }
+
+    ItemEnum {
+        name: field_text(node, "name", source),
+        variants,
+        byte_range: node.byte_range(),
+    }
+}
+
+fn extract_fn(node: Node, source: &str) -> ItemFn {
+    let mut params = Vec::new();
+    if let Some(parameters) = node.child_by_field_name("parameters") {
+        let mut cursor = parameters.walk();
+        for child in parameters.children(&mut cursor) {
+            if child.kind() == "parameter" {
+                params.push(Param {
+                    name: field_text(child, "pattern", source),
+                    ty: field_text(child, "type", source),
+                });
+            }
+        }
+    }
+
+    let return_type = node
+        .child_by_field_name("return_type")
+        .map(|n| text(n, source).to_string());
+
+    ItemFn {
+        name: field_text(node, "name", source),
+        params,
+        return_type,
+        byte_range: node.byte_range(),
+    }
+}
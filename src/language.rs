@@ -0,0 +1,62 @@
+//! Language detection and tree-sitter grammar dispatch.
+
+use tree_sitter::{Parser, Tree};
+
+/// A source language recognized by shannon-insight, with a tree-sitter
+/// grammar behind it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Rust,
+    C,
+    Cpp,
+    Python,
+    JavaScript,
+    Go,
+}
+
+impl Language {
+    /// Maps a file extension (without the leading dot) to a [`Language`],
+    /// returning `None` for extensions we don't recognize.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "rs" => Some(Language::Rust),
+            "c" | "h" => Some(Language::C),
+            "cc" | "cpp" | "cxx" | "hh" | "hpp" => Some(Language::Cpp),
+            "py" => Some(Language::Python),
+            "js" | "jsx" | "mjs" => Some(Language::JavaScript),
+            "go" => Some(Language::Go),
+            _ => None,
+        }
+    }
+
+    /// Maps a file path to a [`Language`] based on its extension.
+    pub fn from_path(path: &std::path::Path) -> Option<Self> {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_extension)
+    }
+
+    /// The tree-sitter grammar for this language.
+    pub(crate) fn grammar(self) -> tree_sitter::Language {
+        match self {
+            Language::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Language::C => tree_sitter_c::LANGUAGE.into(),
+            Language::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+            Language::Python => tree_sitter_python::LANGUAGE.into(),
+            Language::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
+            Language::Go => tree_sitter_go::LANGUAGE.into(),
+        }
+    }
+}
+
+/// Parses `code` with the grammar for `lang`, returning the resulting
+/// tree-sitter [`Tree`].
+pub fn parse_source(code: &str, lang: Language) -> Tree {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&lang.grammar())
+        .expect("grammar version mismatch between tree-sitter and its language crate");
+    parser
+        .parse(code, None)
+        .expect("tree-sitter always returns a tree for non-empty input without cancellation")
+}
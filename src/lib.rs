@@ -0,0 +1,17 @@
+//! shannon-insight: structural code analysis built on tree-sitter.
+
+pub mod diagnostics;
+pub mod error;
+pub mod extract;
+pub mod language;
+pub mod metrics;
+pub mod reparse;
+pub mod search;
+
+pub use diagnostics::{diagnostics, DiagnosticKind, ParseDiagnostic, Severity};
+pub use error::{Error, Result};
+pub use extract::{extract_items, Field, Item, ItemEnum, ItemFn, ItemStruct, ItemTrait, Param, Visibility};
+pub use language::{parse_source, Language};
+pub use metrics::{analyze_complexity, analyze_entropy, file_entropy, ComplexityReport, Entropy, FunctionEntropy};
+pub use reparse::{Edit, ReparseSession};
+pub use search::{search, search_files, Capture, FileMatches, Match};
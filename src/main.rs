@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "shannon-insight", about = "Structural code analysis built on tree-sitter")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a structural-search query over one or more source files.
+    Search {
+        /// Path to a file containing the S-expression query.
+        #[arg(long)]
+        query: PathBuf,
+        /// Source files to search.
+        paths: Vec<PathBuf>,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Search { query, paths } => run_search(&query, &paths)?,
+    }
+    Ok(())
+}
+
+fn run_search(query_path: &PathBuf, paths: &[PathBuf]) -> anyhow::Result<()> {
+    let query_source = std::fs::read_to_string(query_path)?;
+    let results = shannon_insight::search_files(paths, &query_source)?;
+
+    for file in results {
+        for matched in file.matches {
+            for (name, captures) in &matched {
+                for capture in captures {
+                    println!(
+                        "{}:{}..{} {name} = {:?}",
+                        file.path.display(),
+                        capture.byte_range.start,
+                        capture.byte_range.end,
+                        capture.text
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,110 @@
+//! Shannon entropy of the token/node-kind distribution, as a density and
+//! uniformity signal.
+
+use std::collections::HashMap;
+
+use tree_sitter::{Node, Tree};
+
+/// Entropy over a distribution of tree-sitter token kinds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entropy {
+    /// `H = -Σ p_i * log2(p_i)` over the normalized kind frequencies.
+    pub entropy: f64,
+    /// `entropy / log2(distinct_kinds)`, comparable across sizes.
+    pub normalized_entropy: f64,
+    pub distinct_kinds: usize,
+    pub token_count: usize,
+}
+
+/// Entropy of a single function's token distribution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionEntropy {
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub entropy: Entropy,
+}
+
+/// Entropy of the whole file's token distribution.
+pub fn file_entropy(tree: &Tree) -> Entropy {
+    let mut counts = HashMap::new();
+    tally_kinds(tree.root_node(), &mut counts);
+    Entropy::from_counts(&counts)
+}
+
+/// Entropy of each `function_item` in `tree`.
+pub fn analyze_entropy(tree: &Tree, source: &str) -> Vec<FunctionEntropy> {
+    let mut reports = Vec::new();
+    collect_functions(tree.root_node(), source, &mut reports);
+    reports
+}
+
+fn collect_functions(node: Node, source: &str, reports: &mut Vec<FunctionEntropy>) {
+    if node.kind() == "function_item" {
+        let name = node
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+            .unwrap_or("<anonymous>")
+            .to_string();
+
+        let mut counts = HashMap::new();
+        tally_kinds(node, &mut counts);
+
+        reports.push(FunctionEntropy {
+            name,
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            entropy: Entropy::from_counts(&counts),
+        });
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_functions(child, source, reports);
+    }
+}
+
+/// Tallies the frequency of each leaf node's `kind` (identifiers,
+/// operators, literals, keywords) under `node`.
+fn tally_kinds(node: Node, counts: &mut HashMap<&'static str, usize>) {
+    if node.child_count() == 0 {
+        *counts.entry(node.kind()).or_insert(0) += 1;
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        tally_kinds(child, counts);
+    }
+}
+
+impl Entropy {
+    fn from_counts(counts: &HashMap<&'static str, usize>) -> Self {
+        let token_count: usize = counts.values().sum();
+        let distinct_kinds = counts.len();
+
+        let entropy = if token_count == 0 {
+            0.0
+        } else {
+            -counts
+                .values()
+                .map(|&count| {
+                    let p = count as f64 / token_count as f64;
+                    p * p.log2()
+                })
+                .sum::<f64>()
+        };
+
+        let normalized_entropy = if distinct_kinds > 1 {
+            entropy / (distinct_kinds as f64).log2()
+        } else {
+            0.0
+        };
+
+        Entropy {
+            entropy,
+            normalized_entropy,
+            distinct_kinds,
+            token_count,
+        }
+    }
+}
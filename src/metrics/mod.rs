@@ -0,0 +1,7 @@
+//! Metrics passes over a parsed syntax tree.
+
+pub mod complexity;
+pub mod entropy;
+
+pub use complexity::{analyze_complexity, ComplexityReport};
+pub use entropy::{analyze_entropy, file_entropy, Entropy, FunctionEntropy};
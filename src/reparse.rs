@@ -0,0 +1,128 @@
+//! Incremental reparsing for watch-mode style workflows.
+//!
+//! Modeled on the incremental/full reparse split used by editor tooling
+//! like rust-analyzer: an edit that stays within a single existing leaf
+//! token is reparsed incrementally from the previous tree; an edit that
+//! crosses into the surrounding structure falls back to a full reparse.
+
+use std::ops::Range;
+
+use tree_sitter::{InputEdit, Parser, Point, Tree};
+
+use crate::language::Language;
+
+/// A textual edit, given in both byte offsets and row/column points as
+/// tree-sitter's [`InputEdit`] requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edit {
+    pub start_byte: usize,
+    pub old_end_byte: usize,
+    pub new_end_byte: usize,
+    pub start_point: Point,
+    pub old_end_point: Point,
+    pub new_end_point: Point,
+}
+
+impl From<Edit> for InputEdit {
+    fn from(edit: Edit) -> Self {
+        InputEdit {
+            start_byte: edit.start_byte,
+            old_end_byte: edit.old_end_byte,
+            new_end_byte: edit.new_end_byte,
+            start_position: edit.start_point,
+            old_end_position: edit.old_end_point,
+            new_end_position: edit.new_end_point,
+        }
+    }
+}
+
+/// Holds a parsed tree and its source buffer across a sequence of edits,
+/// reparsing incrementally where possible.
+pub struct ReparseSession {
+    lang: Language,
+    source: String,
+    tree: Tree,
+}
+
+impl ReparseSession {
+    /// Parses `source` as `lang` and starts a new session from the result.
+    pub fn new(source: String, lang: Language) -> Self {
+        let tree = crate::language::parse_source(&source, lang);
+        ReparseSession { lang, source, tree }
+    }
+
+    /// The most recently parsed tree.
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// The source buffer the current tree was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Applies `edit`, producing `new_source`, and reparses. Returns the
+    /// byte ranges that changed between the old and new tree, so callers
+    /// can re-run downstream metrics only on affected subtrees.
+    pub fn reparse(&mut self, new_source: String, edit: Edit) -> Vec<Range<usize>> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&self.lang.grammar())
+            .expect("grammar version mismatch between tree-sitter and its language crate");
+
+        let incremental = edit_fits_in_leaf(&self.tree, &edit);
+
+        // `changed_ranges` requires the old tree to have been edited to
+        // line up with the new tree's byte offsets, so this must happen
+        // on both paths even though only the incremental path feeds the
+        // edited tree to the parser.
+        self.tree.edit(&edit.into());
+
+        let new_tree = if incremental {
+            parser
+                .parse(&new_source, Some(&self.tree))
+                .expect("tree-sitter always returns a tree for non-empty input without cancellation")
+        } else {
+            parser
+                .parse(&new_source, None)
+                .expect("tree-sitter always returns a tree for non-empty input without cancellation")
+        };
+
+        let mut changed_ranges: Vec<Range<usize>> = self
+            .tree
+            .changed_ranges(&new_tree)
+            .map(|range| range.start_byte..range.end_byte)
+            .collect();
+
+        // `changed_ranges` only reports spans whose node structure changed,
+        // so an edit that rewrites text inside one token (e.g. `1` -> `100`)
+        // is added here when no reported range already touches it.
+        let edited = edit.start_byte..edit.new_end_byte;
+        if !changed_ranges
+            .iter()
+            .any(|range| range.start <= edited.end && edited.start <= range.end)
+        {
+            let at = changed_ranges.partition_point(|range| range.start < edited.start);
+            changed_ranges.insert(at, edited);
+        }
+
+        self.source = new_source;
+        self.tree = new_tree;
+        changed_ranges
+    }
+}
+
+/// An edit "fits in a leaf" when the old byte range it replaces is fully
+/// contained within a single childless node of the existing tree — a
+/// token like an identifier or literal. Anything wider (spanning multiple
+/// tokens, or a whole node with children) touches a structural boundary
+/// and needs a full reparse to stay correct.
+fn edit_fits_in_leaf(tree: &Tree, edit: &Edit) -> bool {
+    match tree
+        .root_node()
+        .descendant_for_byte_range(edit.start_byte, edit.old_end_byte)
+    {
+        Some(node) => node.child_count() == 0,
+        None => false,
+    }
+}
@@ -0,0 +1,85 @@
+//! Structural search: run a tree-sitter query against parsed source and
+//! collect the matched captures.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+use tree_sitter::{Query, QueryCursor, StreamingIterator};
+
+use crate::error::{Error, Result};
+use crate::language::{parse_source, Language};
+
+/// A single capture from a structural-search match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capture {
+    pub name: String,
+    pub text: String,
+    pub byte_range: Range<usize>,
+}
+
+/// One match of a structural-search query, keyed by capture name. A name
+/// maps to every node that matched it — a query can capture repeated
+/// siblings (e.g. several fields) under the same name within one match.
+pub type Match = HashMap<String, Vec<Capture>>;
+
+/// The matches found in a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileMatches {
+    pub path: PathBuf,
+    pub matches: Vec<Match>,
+}
+
+/// Runs the S-expression query `query_source` against `source` (parsed as
+/// `lang`) and returns every match as a map from capture name to the
+/// [`Capture`]s bound to that name.
+pub fn search(lang: Language, query_source: &str, source: &str) -> Result<Vec<Match>> {
+    let query = Query::new(&lang.grammar(), query_source)?;
+    let tree = parse_source(source, lang);
+
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(&query, tree.root_node(), source.as_bytes());
+
+    let mut results = Vec::new();
+    while let Some(query_match) = matches.next() {
+        let mut captured = Match::new();
+        for capture in query_match.captures {
+            let name = query.capture_names()[capture.index as usize].to_string();
+            let text = capture
+                .node
+                .utf8_text(source.as_bytes())
+                .unwrap_or_default()
+                .to_string();
+            captured.entry(name.clone()).or_default().push(Capture {
+                name,
+                text,
+                byte_range: capture.node.byte_range(),
+            });
+        }
+        results.push(captured);
+    }
+    Ok(results)
+}
+
+/// Runs `query_source` against every file in `paths`, dispatching each to
+/// its [`Language`] by extension and searching in parallel with rayon.
+/// Files with an unrecognized extension are skipped.
+pub fn search_files(paths: &[PathBuf], query_source: &str) -> Result<Vec<FileMatches>> {
+    paths
+        .par_iter()
+        .filter_map(|path| Language::from_path(path).map(|lang| (path, lang)))
+        .map(|(path, lang)| search_file(path, lang, query_source))
+        .collect()
+}
+
+fn search_file(path: &Path, lang: Language, query_source: &str) -> Result<FileMatches> {
+    let source = std::fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(FileMatches {
+        path: path.to_path_buf(),
+        matches: search(lang, query_source, &source)?,
+    })
+}
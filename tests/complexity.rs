@@ -0,0 +1,55 @@
+use shannon_insight::{analyze_complexity, parse_source, Language};
+
+#[test]
+fn reports_cyclomatic_and_cognitive_complexity_for_nested_control_flow() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let tree = parse_source(&source, Language::Rust);
+    let reports = analyze_complexity(&tree, &source);
+
+    let process_data = reports
+        .iter()
+        .find(|r| r.name == "process_data")
+        .expect("process_data should be analyzed");
+
+    // 1 (base) + for + if + for = 4.
+    assert_eq!(process_data.cyclomatic, 4);
+    // for@0 (1+0) + if@1 (1+1) + for@2 (1+2) = 1 + 2 + 3 = 6.
+    assert_eq!(process_data.cognitive, 6);
+    assert_eq!(process_data.max_nesting, 3);
+}
+
+#[test]
+fn sequential_loops_score_lower_cognitively_than_nested_ones() {
+    let source = r#"
+fn sequential(data: &[i32]) -> i32 {
+    let mut sum = 0;
+    for v in data {
+        sum += v;
+    }
+    for v in data {
+        sum += v;
+    }
+    sum
+}
+"#;
+    let tree = parse_source(source, Language::Rust);
+    let reports = analyze_complexity(&tree, source);
+    let sequential = &reports[0];
+
+    // Two independent loops: 1 (base) + 1 + 1 cyclomatic, but each loop
+    // contributes only its own (1 + 0) cognitively, not a nesting penalty.
+    assert_eq!(sequential.cyclomatic, 3);
+    assert_eq!(sequential.cognitive, 2);
+    assert_eq!(sequential.max_nesting, 1);
+}
+
+#[test]
+fn exceeds_threshold_flags_functions_above_the_configured_limit() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let tree = parse_source(&source, Language::Rust);
+    let reports = analyze_complexity(&tree, &source);
+    let process_data = reports.iter().find(|r| r.name == "process_data").unwrap();
+
+    assert!(process_data.exceeds_threshold(3));
+    assert!(!process_data.exceeds_threshold(10));
+}
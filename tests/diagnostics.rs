@@ -0,0 +1,34 @@
+use shannon_insight::{diagnostics, parse_source, DiagnosticKind, Language};
+
+#[test]
+fn clean_source_yields_no_diagnostics() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let tree = parse_source(&source, Language::Rust);
+    assert!(diagnostics(&tree, &source).is_empty());
+}
+
+#[test]
+fn a_missing_semicolon_is_reported_as_missing() {
+    let source = "fn f() { let x = 1 }";
+    let tree = parse_source(source, Language::Rust);
+    let diags = diagnostics(&tree, source);
+
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].kind, DiagnosticKind::Missing);
+    assert_eq!(diags[0].snippet, source);
+}
+
+#[test]
+fn garbled_input_is_reported_as_unexpected_with_recovery() {
+    let source = "fn f( { ) &&& ";
+    let tree = parse_source(source, Language::Rust);
+    let diags = diagnostics(&tree, source);
+
+    assert!(!diags.is_empty());
+    assert!(diags.iter().any(|d| d.kind == DiagnosticKind::Unexpected));
+
+    // Recovery still yields a tree, with the broken function wrapped in a
+    // top-level ERROR node.
+    let root = tree.root_node();
+    assert!(root.child(0).unwrap().is_error());
+}
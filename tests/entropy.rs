@@ -0,0 +1,53 @@
+use shannon_insight::{analyze_entropy, file_entropy, parse_source, Language};
+
+#[test]
+fn file_entropy_is_nonnegative_and_normalized_bounded() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let tree = parse_source(&source, Language::Rust);
+    let entropy = file_entropy(&tree);
+
+    assert!(entropy.entropy >= 0.0);
+    assert!((0.0..=1.0).contains(&entropy.normalized_entropy));
+    assert!(entropy.distinct_kinds > 1);
+    assert!(entropy.token_count > 0);
+}
+
+#[test]
+fn analyze_entropy_reports_one_entry_per_function() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let tree = parse_source(&source, Language::Rust);
+    let reports = analyze_entropy(&tree, &source);
+
+    let names: Vec<&str> = reports.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["new", "greet", "process_data", "main"]);
+}
+
+#[test]
+fn repetitive_code_has_lower_normalized_entropy_than_varied_code() {
+    let repetitive = r#"
+fn repetitive() -> i32 {
+    let a = 1;
+    let b = 1;
+    let c = 1;
+    a + b + c
+}
+"#;
+    let varied = r#"
+fn varied(x: i32, y: &str) -> bool {
+    if x > 0 {
+        println!("{}", y);
+        true
+    } else {
+        false
+    }
+}
+"#;
+
+    let repetitive_tree = parse_source(repetitive, Language::Rust);
+    let varied_tree = parse_source(varied, Language::Rust);
+
+    let repetitive_entropy = analyze_entropy(&repetitive_tree, repetitive)[0].entropy;
+    let varied_entropy = analyze_entropy(&varied_tree, varied)[0].entropy;
+
+    assert!(repetitive_entropy.normalized_entropy < varied_entropy.normalized_entropy);
+}
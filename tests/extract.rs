@@ -0,0 +1,61 @@
+use shannon_insight::{extract_items, parse_source, Item, Language, Visibility};
+
+#[test]
+fn extracts_typed_items_from_the_sample_fixture() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let tree = parse_source(&source, Language::Rust);
+    let items = extract_items(&tree, &source);
+
+    let traits: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Trait(t) => Some(t),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(traits.len(), 1);
+    assert_eq!(traits[0].name, "Greeter");
+    assert_eq!(traits[0].methods, vec!["greet"]);
+
+    let structs: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(s) => Some(s),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(structs.len(), 1);
+    assert_eq!(structs[0].name, "HelloGreeter");
+    assert_eq!(structs[0].visibility, Visibility::Public);
+    assert_eq!(structs[0].fields.len(), 1);
+    assert_eq!(structs[0].fields[0].name, "prefix");
+    assert_eq!(structs[0].fields[0].ty, "String");
+    assert_eq!(structs[0].fields[0].visibility, Visibility::Private);
+
+    let enums: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Enum(e) => Some(e),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(enums.len(), 1);
+    assert_eq!(enums[0].name, "Status");
+    assert_eq!(enums[0].variants, vec!["Active", "Inactive", "Pending"]);
+
+    let functions: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Fn(f) => Some(f),
+            _ => None,
+        })
+        .collect();
+    let process_data = functions
+        .iter()
+        .find(|f| f.name == "process_data")
+        .unwrap();
+    assert_eq!(process_data.params.len(), 1);
+    assert_eq!(process_data.params[0].name, "data");
+    assert_eq!(process_data.params[0].ty, "&[i32]");
+    assert_eq!(process_data.return_type.as_deref(), Some("i32"));
+}
@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use shannon_insight::{parse_source, Language};
+
+#[test]
+fn from_extension_covers_supported_languages() {
+    assert_eq!(Language::from_extension("rs"), Some(Language::Rust));
+    assert_eq!(Language::from_extension("c"), Some(Language::C));
+    assert_eq!(Language::from_extension("cpp"), Some(Language::Cpp));
+    assert_eq!(Language::from_extension("py"), Some(Language::Python));
+    assert_eq!(Language::from_extension("js"), Some(Language::JavaScript));
+    assert_eq!(Language::from_extension("go"), Some(Language::Go));
+    assert_eq!(Language::from_extension("rb"), None);
+}
+
+#[test]
+fn from_path_dispatches_on_extension() {
+    let path = Path::new("tests/fixtures/sample.rs");
+    assert_eq!(Language::from_path(path), Some(Language::Rust));
+}
+
+#[test]
+fn parse_source_builds_a_tree_for_the_sample_fixture() {
+    let code = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let tree = parse_source(&code, Language::Rust);
+    let root = tree.root_node();
+
+    assert_eq!(root.kind(), "source_file");
+    assert!(!root.has_error());
+}
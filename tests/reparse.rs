@@ -0,0 +1,87 @@
+use tree_sitter::Point;
+
+use shannon_insight::{Edit, Language, ReparseSession};
+
+#[test]
+fn widening_a_literal_reparses_incrementally() {
+    let old_source = "fn foo() -> i32 { 1 }".to_string();
+    let mut session = ReparseSession::new(old_source, Language::Rust);
+
+    // Replace the literal "1" (byte 18..19) with "100", entirely within
+    // that single token.
+    let new_source = "fn foo() -> i32 { 100 }".to_string();
+    let edit = Edit {
+        start_byte: 18,
+        old_end_byte: 19,
+        new_end_byte: 21,
+        start_point: Point { row: 0, column: 18 },
+        old_end_point: Point { row: 0, column: 19 },
+        new_end_point: Point { row: 0, column: 21 },
+    };
+
+    let changed = session.reparse(new_source.clone(), edit);
+
+    assert!(!changed.is_empty());
+    assert_eq!(session.source(), new_source);
+    assert!(!session.tree().root_node().has_error());
+
+    let function = session.tree().root_node().child(0).unwrap();
+    let name = function.child_by_field_name("name").unwrap();
+    assert_eq!(&new_source[name.byte_range()], "foo");
+}
+
+#[test]
+fn appending_a_new_item_falls_back_to_a_full_reparse() {
+    let old_source = "fn foo() -> i32 { 1 }".to_string();
+    let mut session = ReparseSession::new(old_source.clone(), Language::Rust);
+
+    let addition = "\nfn bar() -> i32 { 2 }";
+    let new_source = format!("{old_source}{addition}");
+    let edit = Edit {
+        start_byte: old_source.len(),
+        old_end_byte: old_source.len(),
+        new_end_byte: new_source.len(),
+        start_point: Point { row: 0, column: old_source.len() },
+        old_end_point: Point { row: 0, column: old_source.len() },
+        new_end_point: Point { row: 1, column: addition.len() - 1 },
+    };
+
+    let changed = session.reparse(new_source.clone(), edit);
+
+    assert!(!changed.is_empty());
+    assert!(!session.tree().root_node().has_error());
+    assert_eq!(session.tree().root_node().child_count(), 2);
+}
+
+#[test]
+fn inserting_an_item_in_the_middle_reports_the_actual_inserted_range() {
+    // Regression test: a structural edit that isn't at the very end of
+    // the file, so that `changed_ranges` only lines up with the new tree
+    // if the old tree was `.edit()`-ed before reparsing. Without that
+    // edit, tree-sitter compares against stale byte
+    // offsets and reports a range that spans into unrelated, unchanged
+    // code instead of just the inserted span.
+    let line1 = "fn foo() -> i32 { 1 }\n";
+    let line2 = "fn bar() -> i32 { 2 }";
+    let old_source = format!("{line1}{line2}");
+    let mut session = ReparseSession::new(old_source.clone(), Language::Rust);
+
+    let insertion = "fn baz() -> i32 { 3 }\n";
+    let new_source = format!("{line1}{insertion}{line2}");
+
+    let start_byte = line1.len();
+    let edit = Edit {
+        start_byte,
+        old_end_byte: start_byte,
+        new_end_byte: start_byte + insertion.len(),
+        start_point: Point { row: 1, column: 0 },
+        old_end_point: Point { row: 1, column: 0 },
+        new_end_point: Point { row: 2, column: 0 },
+    };
+
+    let changed = session.reparse(new_source.clone(), edit);
+
+    assert_eq!(changed, vec![22..43]);
+    assert!(!session.tree().root_node().has_error());
+    assert_eq!(session.tree().root_node().child_count(), 3);
+}
@@ -0,0 +1,51 @@
+use shannon_insight::{search, search_files, Language};
+
+const STRUCT_QUERY: &str = "(struct_item name: (type_identifier) @name)";
+
+#[test]
+fn search_finds_struct_names_in_source() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let matches = search(Language::Rust, STRUCT_QUERY, &source).unwrap();
+
+    let names: Vec<&str> = matches
+        .iter()
+        .map(|m| m["name"][0].text.as_str())
+        .collect();
+    assert_eq!(names, vec!["HelloGreeter"]);
+}
+
+#[test]
+fn search_keeps_every_capture_for_a_repeated_name_in_one_match() {
+    let source = "struct S { a: i32, b: i32 }";
+    let query = "(field_declaration_list \
+        (field_declaration name: (field_identifier) @field) \
+        (field_declaration name: (field_identifier) @field))";
+    let matches = search(Language::Rust, query, source).unwrap();
+
+    assert_eq!(matches.len(), 1);
+    let fields: Vec<&str> = matches[0]["field"]
+        .iter()
+        .map(|capture| capture.text.as_str())
+        .collect();
+    assert_eq!(fields, vec!["a", "b"]);
+}
+
+#[test]
+fn search_rejects_malformed_queries() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.rs").unwrap();
+    let err = search(Language::Rust, "(not a real query", &source).unwrap_err();
+    assert!(matches!(err, shannon_insight::Error::Query(_)));
+}
+
+#[test]
+fn search_files_dispatches_by_extension_and_skips_unknown() {
+    let paths = vec![
+        "tests/fixtures/sample.rs".into(),
+        "tests/fixtures/does_not_exist.xyz".into(),
+    ];
+    let results = search_files(&paths, STRUCT_QUERY).unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].path, std::path::Path::new("tests/fixtures/sample.rs"));
+    assert_eq!(results[0].matches.len(), 1);
+}